@@ -1,23 +1,65 @@
-use tauri::{TitleBarStyle, WebviewUrl, WebviewWindowBuilder};
+mod notes;
+mod pin;
+mod quick_capture;
+mod tray;
+mod window_chrome;
+mod window_state;
+
+#[cfg(target_os = "macos")]
+use window_chrome::TitlebarStyle;
+
+use tauri::{WebviewUrl, WebviewWindowBuilder, WindowEvent};
 
 pub fn run() {
+    // `--hidden` boots the app straight into the tray, with the main window never shown.
+    let start_hidden = std::env::args().any(|arg| arg == "--hidden");
+
     tauri::Builder::default()
         .plugin(tauri_plugin_websocket::init())
         .plugin(tauri_plugin_store::Builder::new().build())
-        .setup(|app| {
-            let win_builder = WebviewWindowBuilder::new(app, "main", WebviewUrl::default())
-                .title("Transparent Titlebar Window")
-                .inner_size(800.0, 600.0);
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .invoke_handler(tauri::generate_handler![
+            quick_capture::get_quick_capture_shortcut,
+            quick_capture::set_quick_capture_shortcut,
+            quick_capture::dismiss_quick_capture,
+            window_chrome::set_titlebar_style,
+            window_chrome::start_dragging,
+            window_chrome::minimize_window,
+            window_chrome::toggle_maximize_window,
+            window_chrome::close_window,
+            pin::toggle_pin_window,
+            notes::open_note_window,
+        ])
+        .on_window_event(|window, event| {
+            // The tray keeps NoteLens resident: closing the main window hides it instead
+            // of quitting, and only the tray's Quit item calls `app.exit`.
+            if window.label() == tray::MAIN_WINDOW {
+                if let WindowEvent::CloseRequested { api, .. } = event {
+                    api.prevent_default();
+                    let _ = window.hide();
+                }
+            }
+        })
+        .setup(move |app| {
+            let win_builder =
+                WebviewWindowBuilder::new(app, "main", WebviewUrl::default())
+                    .title("Transparent Titlebar Window")
+                    .visible(!start_hidden);
+            let win_builder = window_state::with_saved_geometry(app, "main", win_builder);
 
-            // set transparent title bar only when building for macOS
-            #[cfg(target_os = "macos")]
-            let win_builder = win_builder.title_bar_style(TitleBarStyle::Transparent);
+            let titlebar_style = window_chrome::configured_style(app);
+            let win_builder = window_chrome::apply(titlebar_style, win_builder);
 
             let window = win_builder.build().unwrap();
 
-            // set background color only when building for macOS
+            window_state::finish_setup(app, &window);
+            pin::restore(app.handle(), &window);
+            tray::build(app)?;
+            quick_capture::init(app)?;
+
+            // set background color only when using the macOS native-inset titlebar
             #[cfg(target_os = "macos")]
-            {
+            if titlebar_style == TitlebarStyle::NativeInset {
                 use cocoa::appkit::{NSColor, NSWindow};
                 use cocoa::base::{id, nil};
 
@@ -0,0 +1,76 @@
+//! Opens individual notes in their own webview windows, keyed by note id.
+//!
+//! The existence check and window creation both happen synchronously on the main thread
+//! inside [`open_note_window`], via `get_webview_window` rather than an in-memory
+//! registry — Tauri already tracks open windows by label, so a second, separately
+//! maintained set would just be redundant state to keep in sync. Tauri's window lookup
+//! and builder calls must run on the main thread; awaiting between the lookup and the
+//! build (e.g. an async `getByLabel` round-trip from the frontend followed by a separate
+//! build call) re-enters the event loop and recurses, which overflows the main thread
+//! stack on Windows.
+
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+
+use crate::window_chrome;
+#[cfg(target_os = "macos")]
+use crate::window_chrome::TitlebarStyle;
+
+fn window_label(note_id: &str) -> String {
+    format!("note-{note_id}")
+}
+
+/// Focuses (and unminimizes) the window for `note_id` if one is already open, otherwise
+/// builds a new one. Runs entirely synchronously on the calling (main) thread.
+#[tauri::command]
+pub fn open_note_window(app: AppHandle, note_id: String) -> Result<(), String> {
+    let label = window_label(&note_id);
+
+    if let Some(window) = app.get_webview_window(&label) {
+        if window.is_minimized().unwrap_or(false) {
+            let _ = window.unminimize();
+        }
+        let _ = window.show();
+        let _ = window.set_focus();
+        return Ok(());
+    }
+
+    let style = window_chrome::configured_style(&app);
+    let builder = WebviewWindowBuilder::new(
+        &app,
+        &label,
+        WebviewUrl::App(format!("note.html?id={note_id}").into()),
+    )
+    .title("Note");
+    let builder = window_chrome::apply(style, builder);
+
+    let window = builder.build().map_err(|e| e.to_string())?;
+    crate::pin::restore(&app, &window);
+
+    #[cfg(target_os = "macos")]
+    if style == TitlebarStyle::NativeInset {
+        apply_macos_titlebar(&window);
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn apply_macos_titlebar(window: &tauri::WebviewWindow) {
+    use cocoa::appkit::{NSColor, NSWindow};
+    use cocoa::base::{id, nil};
+
+    let Ok(ns_window) = window.ns_window() else {
+        return;
+    };
+    let ns_window = ns_window as id;
+    unsafe {
+        let bg_color = NSColor::colorWithRed_green_blue_alpha_(
+            nil,
+            255.0 / 255.0,
+            255.0 / 255.0,
+            255.0 / 255.0,
+            1.0,
+        );
+        ns_window.setBackgroundColor_(bg_color);
+    }
+}
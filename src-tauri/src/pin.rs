@@ -0,0 +1,60 @@
+//! Always-on-top "pin" mode: keeps a window floating over other apps and visible across
+//! every virtual desktop / macOS Space, with the pinned state persisted per window label
+//! so it's restored the next time that window is (re)opened.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, WebviewWindow};
+use tauri_plugin_store::StoreExt;
+
+const STORE_FILE: &str = "window-pin.json";
+const PINNED_KEY: &str = "pinned";
+
+fn pinned_windows(app: &AppHandle) -> HashMap<String, bool> {
+    let Ok(store) = app.store(STORE_FILE) else {
+        return HashMap::new();
+    };
+    store
+        .get(PINNED_KEY)
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+fn save_pinned(app: &AppHandle, pinned: &HashMap<String, bool>) {
+    let Ok(store) = app.store(STORE_FILE) else {
+        return;
+    };
+    if let Ok(value) = serde_json::to_value(pinned) {
+        store.set(PINNED_KEY, value);
+        let _ = store.save();
+    }
+}
+
+fn apply(window: &WebviewWindow, pinned: bool) {
+    let _ = window.set_always_on_top(pinned);
+    let _ = window.set_visible_on_all_workspaces(pinned);
+}
+
+/// Re-applies a previously saved pinned state to `window`, if any was persisted for its
+/// label. Called once after a window is built.
+pub fn restore(app: &AppHandle, window: &WebviewWindow) {
+    if pinned_windows(app).get(window.label()).copied().unwrap_or(false) {
+        apply(window, true);
+    }
+}
+
+/// Toggles always-on-top + visible-on-all-workspaces for `window` and persists the new
+/// state. Returns the state pinning was toggled to.
+#[tauri::command]
+pub fn toggle_pin_window(app: AppHandle, window: WebviewWindow) -> Result<bool, String> {
+    let label = window.label().to_string();
+    let mut pinned = pinned_windows(&app);
+    let now_pinned = !pinned.get(&label).copied().unwrap_or(false);
+
+    apply(&window, now_pinned);
+    pinned.insert(label, now_pinned);
+    save_pinned(&app, &pinned);
+
+    Ok(now_pinned)
+}
@@ -0,0 +1,113 @@
+//! Global-hotkey quick-capture window: a small always-on-top note window that can be
+//! summoned from anywhere, Spotlight-style. Captured notes are sent to the frontend
+//! over the existing websocket plugin channel like any other note.
+
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindow, WebviewWindowBuilder, WindowEvent};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_plugin_store::StoreExt;
+
+pub const QUICK_CAPTURE_WINDOW: &str = "quick-capture";
+const STORE_FILE: &str = "quick-capture.json";
+const SHORTCUT_KEY: &str = "shortcut";
+const DEFAULT_SHORTCUT: &str = "CmdOrCtrl+Shift+N";
+
+/// Builds the (hidden) quick-capture window and registers the saved hotkey, falling
+/// back to [`DEFAULT_SHORTCUT`] on first launch.
+pub fn init(app: &tauri::App) -> tauri::Result<()> {
+    build_window(app)?;
+
+    let combo = saved_shortcut(app.handle()).unwrap_or_else(|| DEFAULT_SHORTCUT.to_string());
+    if let Err(err) = register(app.handle(), &combo) {
+        log::error!("failed to register quick-capture shortcut \"{combo}\": {err}");
+    }
+
+    Ok(())
+}
+
+fn build_window(app: &tauri::App) -> tauri::Result<WebviewWindow> {
+    let window = WebviewWindowBuilder::new(
+        app,
+        QUICK_CAPTURE_WINDOW,
+        WebviewUrl::App("quick-capture.html".into()),
+    )
+    .title("Quick Capture")
+    .inner_size(480.0, 160.0)
+    .decorations(false)
+    .always_on_top(true)
+    .skip_taskbar(true)
+    .visible(false)
+    .build()?;
+
+    let dismiss = window.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Focused(false) = event {
+            let _ = dismiss.hide();
+        }
+    });
+
+    Ok(window)
+}
+
+fn toggle(app: &AppHandle) {
+    let Some(window) = app.get_webview_window(QUICK_CAPTURE_WINDOW) else {
+        return;
+    };
+    if window.is_visible().unwrap_or(false) {
+        let _ = window.hide();
+    } else {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+fn saved_shortcut(app: &AppHandle) -> Option<String> {
+    let store = app.store(STORE_FILE).ok()?;
+    store.get(SHORTCUT_KEY)?.as_str().map(str::to_string)
+}
+
+fn save_shortcut(app: &AppHandle, combo: &str) {
+    let Ok(store) = app.store(STORE_FILE) else {
+        return;
+    };
+    store.set(SHORTCUT_KEY, combo);
+    let _ = store.save();
+}
+
+fn register(app: &AppHandle, combo: &str) -> Result<(), String> {
+    let shortcut: Shortcut = combo
+        .parse()
+        .map_err(|e| format!("invalid shortcut \"{combo}\": {e}"))?;
+
+    let manager = app.global_shortcut();
+    // The user may be switching combos, so drop whatever was previously bound first.
+    let _ = manager.unregister_all();
+    manager
+        .on_shortcut(shortcut, move |app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                toggle(app);
+            }
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Changes the quick-capture hotkey, surfacing registration failures (e.g. the combo is
+/// already claimed by the OS) back to the frontend instead of panicking.
+#[tauri::command]
+pub fn set_quick_capture_shortcut(app: AppHandle, combo: String) -> Result<(), String> {
+    register(&app, &combo)?;
+    save_shortcut(&app, &combo);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_quick_capture_shortcut(app: AppHandle) -> String {
+    saved_shortcut(&app).unwrap_or_else(|| DEFAULT_SHORTCUT.to_string())
+}
+
+/// Hides the quick-capture window; called by the frontend when the user presses Escape.
+#[tauri::command]
+pub fn dismiss_quick_capture(app: AppHandle) {
+    if let Some(window) = app.get_webview_window(QUICK_CAPTURE_WINDOW) {
+        let _ = window.hide();
+    }
+}
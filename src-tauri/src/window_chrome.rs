@@ -0,0 +1,89 @@
+//! Cross-platform frameless window chrome. Decorations are off everywhere by default so
+//! the frontend can render its own titlebar and drag region; macOS can instead opt into
+//! its native inset traffic-light style via [`TitlebarStyle::NativeInset`].
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Runtime, WebviewWindow, WebviewWindowBuilder};
+use tauri_plugin_store::StoreExt;
+
+const STORE_FILE: &str = "window-chrome.json";
+const STYLE_KEY: &str = "titlebar-style";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TitlebarStyle {
+    /// No native decorations; the frontend draws its own titlebar and controls.
+    Custom,
+    /// macOS's inset traffic lights over a transparent titlebar.
+    NativeInset,
+}
+
+impl Default for TitlebarStyle {
+    fn default() -> Self {
+        TitlebarStyle::Custom
+    }
+}
+
+/// Reads the configured style from any manager handle — `&App` inside `.setup()`, or
+/// `&AppHandle` from a command handler.
+pub fn configured_style<R: Runtime, M: Manager<R>>(manager: &M) -> TitlebarStyle {
+    let Ok(store) = manager.store(STORE_FILE) else {
+        return TitlebarStyle::default();
+    };
+    store
+        .get(STYLE_KEY)
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+/// Applies `style` to `builder`. `NativeInset` only has an effect on macOS; every other
+/// platform always gets the fully custom frameless chrome.
+pub fn apply<'a, R: Runtime, M: Manager<R>>(
+    style: TitlebarStyle,
+    builder: WebviewWindowBuilder<'a, R, M>,
+) -> WebviewWindowBuilder<'a, R, M> {
+    #[cfg(target_os = "macos")]
+    {
+        if style == TitlebarStyle::NativeInset {
+            return builder.title_bar_style(tauri::TitleBarStyle::Transparent);
+        }
+    }
+
+    let _ = style;
+    builder.decorations(false)
+}
+
+/// Changes which titlebar style new windows are built with. Windows that are already open
+/// keep their current chrome, so the main window picks up the change on next launch.
+#[tauri::command]
+pub fn set_titlebar_style(app: AppHandle, style: TitlebarStyle) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    let value = serde_json::to_value(style).map_err(|e| e.to_string())?;
+    store.set(STYLE_KEY, value);
+    store.save().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn start_dragging(window: WebviewWindow) -> Result<(), String> {
+    window.start_dragging().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn minimize_window(window: WebviewWindow) -> Result<(), String> {
+    window.minimize().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn toggle_maximize_window(window: WebviewWindow) -> Result<(), String> {
+    let maximized = window.is_maximized().map_err(|e| e.to_string())?;
+    if maximized {
+        window.unmaximize().map_err(|e| e.to_string())
+    } else {
+        window.maximize().map_err(|e| e.to_string())
+    }
+}
+
+#[tauri::command]
+pub fn close_window(window: WebviewWindow) -> Result<(), String> {
+    window.close().map_err(|e| e.to_string())
+}
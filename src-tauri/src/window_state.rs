@@ -0,0 +1,177 @@
+//! Persists window geometry (size, position, maximized/fullscreen) across restarts
+//! using the app's `tauri-plugin-store` instance.
+
+use serde::{Deserialize, Serialize};
+use tauri::{
+    AppHandle, LogicalPosition, LogicalSize, Manager, Monitor, PhysicalPosition, PhysicalSize,
+    WebviewWindow, WebviewWindowBuilder, WindowEvent,
+};
+use tauri_plugin_store::StoreExt;
+
+const STORE_FILE: &str = "window-state.json";
+const DEFAULT_WIDTH: f64 = 800.0;
+const DEFAULT_HEIGHT: f64 = 600.0;
+
+/// Size and position are stored in *logical* pixels, matching the units the
+/// `WebviewWindowBuilder::inner_size`/`position` restore calls expect; `outer_position`/
+/// `inner_size` return physical pixels, so saving must convert through the window's
+/// scale factor or geometry would double/halve on any non-1.0 scale display.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct SavedState {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    maximized: bool,
+    fullscreen: bool,
+}
+
+fn load(app: &AppHandle, label: &str) -> Option<SavedState> {
+    let store = app.store(STORE_FILE).ok()?;
+    serde_json::from_value(store.get(label)?).ok()
+}
+
+fn save(app: &AppHandle, label: &str, state: SavedState) {
+    let Ok(store) = app.store(STORE_FILE) else {
+        return;
+    };
+    if let Ok(value) = serde_json::to_value(state) {
+        store.set(label, value);
+        let _ = store.save();
+    }
+}
+
+/// Applies the saved size and position for `label` to `builder`, falling back to the
+/// 800x600 default when nothing has been saved yet.
+pub fn with_saved_geometry<'a>(
+    app: &'a tauri::App,
+    label: &str,
+    builder: WebviewWindowBuilder<'a, tauri::Wry, tauri::App>,
+) -> WebviewWindowBuilder<'a, tauri::Wry, tauri::App> {
+    let saved = load(app.handle(), label);
+    let (width, height) = saved
+        .map(|s| (s.width, s.height))
+        .unwrap_or((DEFAULT_WIDTH, DEFAULT_HEIGHT));
+    let builder = builder.inner_size(width, height);
+
+    match saved {
+        Some(state) => builder.position(state.x, state.y),
+        None => builder,
+    }
+}
+
+/// Restores maximized/fullscreen flags and clamps an off-screen position back onto a
+/// visible monitor, then starts watching `window` for future geometry changes.
+pub fn finish_setup(app: &tauri::App, window: &WebviewWindow) {
+    if let Some(state) = load(app.handle(), window.label()) {
+        clamp_to_visible_monitor(window, state);
+        if state.maximized {
+            let _ = window.maximize();
+        }
+        if state.fullscreen {
+            let _ = window.set_fullscreen(true);
+        }
+    }
+
+    watch(window.clone());
+}
+
+fn clamp_to_visible_monitor(window: &WebviewWindow, state: SavedState) {
+    let Ok(monitors) = window.available_monitors() else {
+        return;
+    };
+    let scale = window.scale_factor().unwrap_or(1.0);
+    let pos = LogicalPosition::new(state.x, state.y).to_physical::<i32>(scale);
+    let size = LogicalSize::new(state.width, state.height).to_physical::<u32>(scale);
+    if monitors.iter().any(|m| monitor_contains(m, pos, size)) {
+        return;
+    }
+
+    // Saved position is off every connected display; move onto whichever monitor is
+    // closest to where the window used to be, clamped so it stays fully on-screen.
+    let Some(nearest) = nearest_monitor(&monitors, pos) else {
+        return;
+    };
+    let m_pos = nearest.position();
+    let m_size = nearest.size();
+    let max_x = m_pos.x + (m_size.width as i32 - size.width as i32).max(0);
+    let max_y = m_pos.y + (m_size.height as i32 - size.height as i32).max(0);
+    let clamped = PhysicalPosition::new(pos.x.clamp(m_pos.x, max_x), pos.y.clamp(m_pos.y, max_y));
+    let _ = window.set_position(clamped);
+}
+
+fn nearest_monitor<'a>(monitors: &'a [Monitor], pos: PhysicalPosition<i32>) -> Option<&'a Monitor> {
+    monitors.iter().min_by_key(|m| {
+        let m_pos = m.position();
+        let m_size = m.size();
+        let center_x = m_pos.x + m_size.width as i32 / 2;
+        let center_y = m_pos.y + m_size.height as i32 / 2;
+        let dx = (pos.x - center_x) as i64;
+        let dy = (pos.y - center_y) as i64;
+        dx * dx + dy * dy
+    })
+}
+
+fn monitor_contains(monitor: &Monitor, pos: PhysicalPosition<i32>, size: PhysicalSize<u32>) -> bool {
+    let m_pos = monitor.position();
+    let m_size = monitor.size();
+    let window_right = pos.x + size.width as i32;
+    let window_bottom = pos.y + size.height as i32;
+    let monitor_right = m_pos.x + m_size.width as i32;
+    let monitor_bottom = m_pos.y + m_size.height as i32;
+
+    window_right > m_pos.x && pos.x < monitor_right && window_bottom > m_pos.y && pos.y < monitor_bottom
+}
+
+fn watch(window: WebviewWindow) {
+    let label = window.label().to_string();
+    let handle = window.app_handle().clone();
+    let watched = window.clone();
+    window.on_window_event(move |event| match event {
+        WindowEvent::Moved(_) | WindowEvent::Resized(_) | WindowEvent::CloseRequested { .. } => {
+            persist_geometry(&handle, &label, &watched);
+        }
+        _ => {}
+    });
+}
+
+fn persist_geometry(app: &AppHandle, label: &str, window: &WebviewWindow) {
+    let maximized = window.is_maximized().unwrap_or(false);
+    let fullscreen = window.is_fullscreen().unwrap_or(false);
+
+    // Start from whatever was last saved so the maximized/fullscreen branch below only
+    // updates the flags, keeping the pre-maximize geometry intact.
+    let mut state = load(app, label).unwrap_or(SavedState {
+        x: 0.0,
+        y: 0.0,
+        width: DEFAULT_WIDTH,
+        height: DEFAULT_HEIGHT,
+        maximized,
+        fullscreen,
+    });
+    state.maximized = maximized;
+    state.fullscreen = fullscreen;
+
+    // While maximized/fullscreen, outer_position/inner_size report the maximized
+    // dimensions, not the window's restored geometry — saving them here would make
+    // un-maximizing on next launch return to the maximized size instead.
+    if !maximized && !fullscreen {
+        // Position is the window's outer (decoration-inclusive) top-left, matching what
+        // `.position()` expects on restore; size is the *inner* content size, matching
+        // `.inner_size()` on restore. Both are converted from physical to logical pixels,
+        // since that's what the restore calls take.
+        let (Ok(position), Ok(size)) = (window.outer_position(), window.inner_size()) else {
+            save(app, label, state);
+            return;
+        };
+        let scale = window.scale_factor().unwrap_or(1.0);
+        let logical_position = position.to_logical::<f64>(scale);
+        let logical_size = size.to_logical::<f64>(scale);
+        state.x = logical_position.x;
+        state.y = logical_position.y;
+        state.width = logical_size.width;
+        state.height = logical_size.height;
+    }
+
+    save(app, label, state);
+}